@@ -0,0 +1,82 @@
+use crate::gamma::sample_gamma;
+use crate::UnitInterval;
+
+/// The Beta distribution, whose support is exactly `0.0..=1.0`.
+pub struct Beta<T> {
+    alpha: T,
+    beta: T,
+}
+
+impl<T: PartialOrd + num_traits::Zero> Beta<T> {
+    /// Constructs a `Beta` distribution with shape parameters `alpha` and `beta`, both of
+    /// which must be positive (this also rejects `NaN`).
+    #[inline]
+    pub fn new(alpha: T, beta: T) -> Option<Self> {
+        if alpha > T::zero() && beta > T::zero() {
+            Some(Self { alpha, beta })
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_beta {
+    ($ty:ty) => {
+        impl rand::distributions::Distribution<UnitInterval<$ty>> for Beta<$ty> {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> UnitInterval<$ty> {
+                let x = sample_gamma(rng, self.alpha as f64);
+                let y = sample_gamma(rng, self.beta as f64);
+                let sum = x + y;
+                if sum == 0.0 {
+                    UnitInterval::new_unchecked(0.5 as $ty)
+                } else {
+                    UnitInterval::new_unchecked((x / sum) as $ty)
+                }
+            }
+        }
+    };
+    ($($ty:ty),*) => {
+        $(
+            impl_beta!($ty);
+        )*
+    };
+}
+
+impl_beta!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::distributions::Distribution;
+
+    #[test]
+    fn rejects_non_positive_shape() {
+        assert!(Beta::new(0.0, 1.0).is_none());
+        assert!(Beta::new(1.0, 0.0).is_none());
+        assert!(Beta::new(-1.0, 1.0).is_none());
+        assert!(Beta::new(f64::NAN, 1.0).is_none());
+    }
+
+    #[test]
+    fn samples_stay_in_bounds() {
+        let beta = Beta::new(2.0_f64, 5.0).unwrap();
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let x = beta.sample(&mut rng).get();
+            assert!((0.0..=1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn handles_degenerate_underflow() {
+        // alpha = beta = 1e-3 pushes both Gamma(k, 1) draws to underflow to exactly
+        // 0.0 often enough to hit the `x + y == 0.0` guard within 1000 samples.
+        let beta = Beta::new(1e-3_f64, 1e-3).unwrap();
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let x = beta.sample(&mut rng).get();
+            assert!(!x.is_nan());
+            assert!((0.0..=1.0).contains(&x));
+        }
+    }
+}
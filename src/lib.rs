@@ -1,5 +1,16 @@
 use num_traits::{One, Zero};
 
+mod gamma;
+
+mod beta;
+pub use beta::Beta;
+
+mod triangular;
+pub use triangular::Triangular;
+
+mod simplex;
+pub use simplex::{Dirichlet, Simplex};
+
 // TODO consider adding other ops that return Option<UnitInterval<T>>
 
 /// A value guaranteed to be in the range `0.0..=1.0`
@@ -22,6 +33,15 @@ impl<T> UnitInterval<T> {
     pub fn get(self) -> T {
         self.0
     }
+
+    /// Wraps `value` without checking the `0.0..=1.0` invariant.
+    ///
+    /// Only for use where the invariant is already guaranteed by construction,
+    /// e.g. by deriving the value from other `UnitInterval`s.
+    #[inline]
+    pub(crate) fn new_unchecked(value: T) -> Self {
+        Self(value)
+    }
 }
 
 impl UnitInterval<f32> {
@@ -38,6 +58,37 @@ impl UnitInterval<f64> {
     }
 }
 
+impl<T> UnitInterval<T>
+where
+    T: Zero + One + PartialOrd,
+    rand::distributions::Standard: rand::distributions::Distribution<T>,
+{
+    /// Returns `true` with probability `self.get()`.
+    ///
+    /// Generic (rather than an inherent method per `f32`/`f64`) so that a call on an
+    /// unsuffixed float literal isn't ambiguous between the two.
+    #[inline]
+    pub fn sample_bool<R: rand::Rng + ?Sized>(self, rng: &mut R) -> bool {
+        if self.0 <= T::zero() {
+            false
+        } else if self.0 >= T::one() {
+            true
+        } else {
+            rng.gen::<T>() < self.0
+        }
+    }
+}
+
+impl From<UnitInterval<f64>> for rand::distributions::Bernoulli {
+    /// Unlike [`rand::distributions::Bernoulli::new`], this conversion cannot fail: the
+    /// `UnitInterval` invariant already guarantees `p` is in `0.0..=1.0`.
+    #[inline]
+    fn from(value: UnitInterval<f64>) -> Self {
+        rand::distributions::Bernoulli::new(value.get())
+            .expect("UnitInterval is already guaranteed to be in 0.0..=1.0")
+    }
+}
+
 impl From<UnitInterval<f32>> for UnitInterval<f64> {
     #[inline]
     fn from(value: UnitInterval<f32>) -> Self {
@@ -129,6 +180,72 @@ macro_rules! impl_traits {
 
 impl_traits!(f32, f64);
 
+/// Backing [`rand::distributions::uniform::UniformSampler`] for `UnitInterval<f32>` and
+/// `UnitInterval<f64>`, so `rng.gen_range(lo..=hi)` works for `UnitInterval` bounds.
+pub struct UniformUnitInterval<T>(rand::distributions::uniform::UniformFloat<T>);
+
+macro_rules! impl_sample_uniform {
+    ($ty:ty) => {
+        impl rand::distributions::uniform::SampleUniform for UnitInterval<$ty> {
+            type Sampler = UniformUnitInterval<$ty>;
+        }
+
+        impl rand::distributions::uniform::UniformSampler for UniformUnitInterval<$ty> {
+            type X = UnitInterval<$ty>;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+            {
+                let low = low.borrow().get();
+                let high = high.borrow().get();
+                Self(rand::distributions::uniform::UniformFloat::<$ty>::new(
+                    low, high,
+                ))
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+            {
+                let low = low.borrow().get();
+                let high = high.borrow().get();
+                Self(
+                    rand::distributions::uniform::UniformFloat::<$ty>::new_inclusive(low, high),
+                )
+            }
+
+            #[inline]
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                UnitInterval(self.0.sample(rng))
+            }
+
+            fn sample_single<R: rand::Rng + ?Sized, B1, B2>(low: B1, high: B2, rng: &mut R) -> Self::X
+            where
+                B1: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+                B2: rand::distributions::uniform::SampleBorrow<Self::X> + Sized,
+            {
+                let low = low.borrow().get();
+                let high = high.borrow().get();
+                UnitInterval(
+                    rand::distributions::uniform::UniformFloat::<$ty>::sample_single(
+                        low, high, rng,
+                    ),
+                )
+            }
+        }
+    };
+    ($($ty:ty),*) => {
+        $(
+            impl_sample_uniform!($ty);
+        )*
+    };
+}
+
+impl_sample_uniform!(f32, f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,6 +277,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn gen_range_sub_interval() {
+        use rand::Rng;
+        let mut rng = rand::thread_rng();
+        let lo = UnitInterval::new(0.2).unwrap();
+        let hi = UnitInterval::new(0.8).unwrap();
+        for _ in 0..1000 {
+            let x = rng.gen_range(lo..=hi);
+            assert!(x >= lo);
+            assert!(x <= hi);
+        }
+    }
+
+    #[test]
+    fn sample_bool_edge_cases() {
+        let mut rng = rand::thread_rng();
+        assert!(!UnitInterval::new(0.0).unwrap().sample_bool(&mut rng));
+        assert!(UnitInterval::new(1.0).unwrap().sample_bool(&mut rng));
+    }
+
+    #[test]
+    fn bernoulli_from_unit_interval() {
+        use rand::distributions::{Bernoulli, Distribution};
+        let p = UnitInterval::new(0.25).unwrap();
+        let bernoulli: Bernoulli = p.into();
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let _: bool = bernoulli.sample(&mut rng);
+        }
+    }
+
     #[test]
     #[cfg(not(debug_assertions))]
     fn rand_generates_1() {
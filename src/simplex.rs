@@ -0,0 +1,170 @@
+use crate::gamma::sample_gamma;
+use crate::UnitInterval;
+use num_traits::Float;
+
+/// Tolerance allowed when checking that a [`Simplex`]'s components sum to `1.0`.
+const TOLERANCE: f64 = 1e-6;
+
+/// A vector of [`UnitInterval`]s guaranteed to sum to `1.0` within [`TOLERANCE`].
+///
+/// This is the categorical analogue of `UnitInterval`: where a single `UnitInterval`
+/// guarantees a valid probability, a `Simplex` guarantees a valid probability
+/// distribution over a fixed number of categories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simplex<T>(Vec<UnitInterval<T>>);
+
+impl<T: Float> Simplex<T> {
+    /// Validates that `values` sum to `1.0` within tolerance.
+    pub fn new(values: Vec<UnitInterval<T>>) -> Option<Self> {
+        let sum = values.iter().fold(T::zero(), |acc, v| acc + v.get());
+        let tolerance = T::from(TOLERANCE).unwrap_or_else(T::epsilon);
+        if (sum - T::one()).abs() <= tolerance {
+            Some(Self(values))
+        } else {
+            None
+        }
+    }
+
+    /// Rescales arbitrary non-negative weights into a `Simplex`.
+    pub fn normalize_from(raw: &[T]) -> Option<Self> {
+        if raw.is_empty()
+            || raw
+                .iter()
+                .any(|w| w.is_nan() || w.is_sign_negative() && *w != T::zero())
+        {
+            return None;
+        }
+        let sum = raw.iter().fold(T::zero(), |acc, &w| acc + w);
+        if sum <= T::zero() {
+            return None;
+        }
+        let values = raw
+            .iter()
+            .map(|&w| UnitInterval::new(w / sum))
+            .collect::<Option<Vec<_>>>()?;
+        Self::new(values)
+    }
+
+    /// The components of the simplex.
+    #[inline]
+    pub fn as_slice(&self) -> &[UnitInterval<T>] {
+        &self.0
+    }
+}
+
+/// The Dirichlet distribution, whose support is the probability simplex.
+pub struct Dirichlet<T> {
+    alphas: Vec<T>,
+}
+
+impl<T: Float> Dirichlet<T> {
+    /// A Dirichlet distribution with per-category concentration parameters, each of
+    /// which must be positive (this also rejects `NaN`).
+    pub fn new(alphas: Vec<T>) -> Option<Self> {
+        if alphas.len() >= 2 && alphas.iter().all(|&a| a > T::zero()) {
+            Some(Self { alphas })
+        } else {
+            None
+        }
+    }
+
+    /// A symmetric Dirichlet distribution (all concentrations equal to `1`) over `k`
+    /// categories, i.e. a uniform draw from the probability simplex.
+    pub fn symmetric(k: usize) -> Option<Self> {
+        Self::new(vec![T::one(); k])
+    }
+}
+
+macro_rules! impl_dirichlet {
+    ($ty:ty) => {
+        impl rand::distributions::Distribution<Simplex<$ty>> for Dirichlet<$ty> {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Simplex<$ty> {
+                let draws: Vec<f64> = self
+                    .alphas
+                    .iter()
+                    .map(|&alpha| sample_gamma(rng, alpha as f64))
+                    .collect();
+                let sum: f64 = draws.iter().sum();
+                let values = if sum == 0.0 {
+                    // Degenerate draw (e.g. all gamma samples underflowed to 0 for very
+                    // small alphas): split the mass uniformly rather than dividing by 0.
+                    let uniform = 1.0 / draws.len() as f64;
+                    draws
+                        .into_iter()
+                        .map(|_| UnitInterval::new_unchecked(uniform as $ty))
+                        .collect()
+                } else {
+                    draws
+                        .into_iter()
+                        .map(|x| UnitInterval::new_unchecked((x / sum) as $ty))
+                        .collect()
+                };
+                Simplex(values)
+            }
+        }
+    };
+    ($($ty:ty),*) => {
+        $(
+            impl_dirichlet!($ty);
+        )*
+    };
+}
+
+impl_dirichlet!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::distributions::Distribution;
+
+    #[test]
+    fn new_rejects_components_that_dont_sum_to_one() {
+        let values = vec![
+            UnitInterval::new(0.2).unwrap(),
+            UnitInterval::new(0.3).unwrap(),
+        ];
+        assert!(Simplex::new(values).is_none());
+    }
+
+    #[test]
+    fn normalize_from_rescales_weights() {
+        let simplex = Simplex::normalize_from(&[1.0, 1.0, 2.0]).unwrap();
+        let sum: f64 = simplex.as_slice().iter().map(|v| v.get()).sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn normalize_from_rejects_invalid_weights() {
+        assert!(Simplex::<f64>::normalize_from(&[]).is_none());
+        assert!(Simplex::normalize_from(&[0.0, 0.0]).is_none());
+        assert!(Simplex::normalize_from(&[-1.0, 2.0]).is_none());
+        assert!(Simplex::normalize_from(&[f64::NAN, 1.0]).is_none());
+    }
+
+    #[test]
+    fn dirichlet_samples_sum_to_one() {
+        let dirichlet = Dirichlet::symmetric(3).unwrap();
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let simplex: Simplex<f64> = dirichlet.sample(&mut rng);
+            let sum: f64 = simplex.as_slice().iter().map(|v| v.get()).sum();
+            assert!((sum - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn dirichlet_handles_degenerate_underflow() {
+        // With all three concentrations at 1e-3, each component's Gamma(k, 1) draw
+        // underflows to 0.0 nearly half the time, so all three do in the same round often
+        // enough within 1000 samples to trip Dirichlet::sample's `sum == 0.0` fallback
+        // rather than dividing by it.
+        let dirichlet = Dirichlet::new(vec![1e-3_f64, 1e-3, 1e-3]).unwrap();
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let simplex: Simplex<f64> = dirichlet.sample(&mut rng);
+            for v in simplex.as_slice() {
+                assert!(!v.get().is_nan());
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::UnitInterval;
+
+/// The triangular distribution over `0.0..=1.0`, peaked at a configurable mode.
+pub struct Triangular<T> {
+    mode: UnitInterval<T>,
+}
+
+impl<T> Triangular<T> {
+    /// Constructs a `Triangular` distribution peaked at `mode`.
+    #[inline]
+    pub fn new(mode: UnitInterval<T>) -> Self {
+        Self { mode }
+    }
+}
+
+macro_rules! impl_triangular {
+    ($ty:ty) => {
+        impl rand::distributions::Distribution<UnitInterval<$ty>> for Triangular<$ty> {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> UnitInterval<$ty> {
+                let c = self.mode.get();
+                let u: $ty = rng.gen_range(0.0..=1.0);
+                if u < c {
+                    UnitInterval::new_unchecked((u * c).sqrt())
+                } else {
+                    UnitInterval::new_unchecked(1.0 - ((1.0 - u) * (1.0 - c)).sqrt())
+                }
+            }
+        }
+    };
+    ($($ty:ty),*) => {
+        $(
+            impl_triangular!($ty);
+        )*
+    };
+}
+
+impl_triangular!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::distributions::Distribution;
+
+    #[test]
+    fn samples_stay_in_bounds_for_various_modes() {
+        let mut rng = rand::thread_rng();
+        for mode in [0.0f64, 0.5, 1.0] {
+            let triangular = Triangular::new(UnitInterval::new(mode).unwrap());
+            for _ in 0..1000 {
+                let x = triangular.sample(&mut rng).get();
+                assert!((0.0..=1.0).contains(&x));
+            }
+        }
+    }
+}